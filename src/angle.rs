@@ -0,0 +1,140 @@
+//! A type-safe angle value that can no longer be confused for a bare
+//! radians or degrees float.
+//!
+//! [Angle] always stores its value as radians internally. Build one with
+//! [Angle::radians] or [Angle::degrees], and read it back with [Angle::get]
+//! (radians) or [Angle::to_degrees] (degrees), instead of remembering to call
+//! `.to_radians()`/`.to_degrees()` at every call site. Like [crate::right_angle::RightAngle],
+//! it's generic over [Float] so callers can pick `f32` or `f64` precision.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Float;
+
+/// An angle, stored internally as radians.
+///
+/// # Example
+/// ```rust
+/// use pythagoras::Angle;
+///
+/// let right_angle = Angle::degrees(90.0_f32);
+/// assert_eq!(right_angle.get().round(), 2.0);
+/// assert_eq!(right_angle.to_degrees(), 90.0);
+/// ```
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+#[serde(transparent)]
+pub struct Angle<T: Float = f32> {
+    radians: T,
+}
+
+impl<T: Float> Angle<T> {
+    /// Construct an [Angle] from a value already in radians.
+    pub fn radians(radians: T) -> Self {
+        Self { radians }
+    }
+
+    /// Construct an [Angle] from a value in degrees.
+    pub fn degrees(degrees: T) -> Self {
+        Self {
+            radians: degrees.to_radians(),
+        }
+    }
+
+    /// Returns the angle in radians.
+    pub fn get(&self) -> T {
+        self.radians
+    }
+
+    /// Returns the angle in degrees.
+    pub fn to_degrees(&self) -> T {
+        self.radians.to_degrees()
+    }
+
+    /// Returns the equivalent angle normalized into `[0, 2π)`.
+    pub fn positive(&self) -> Self {
+        let two_pi = T::PI + T::PI;
+        Self::radians(self.radians.rem_euclid(two_pi))
+    }
+
+    /// Returns the equivalent angle normalized into `(-π, π]`.
+    pub fn signed(&self) -> Self {
+        let two_pi = T::PI + T::PI;
+        let positive = self.radians.rem_euclid(two_pi);
+        let radians = if positive > T::PI {
+            positive - two_pi
+        } else {
+            positive
+        };
+        Self::radians(radians)
+    }
+}
+
+impl<T: Float> From<T> for Angle<T> {
+    /// Treats the bare float as radians, matching the rest of this crate's
+    /// existing radians-based API.
+    fn from(radians: T) -> Self {
+        Self::radians(radians)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radians() {
+        let angle = Angle::radians(1.0_f32);
+        assert_eq!(angle.get(), 1.0);
+    }
+
+    #[test]
+    fn test_degrees() {
+        let angle = Angle::degrees(180.0_f32);
+        assert_eq!(
+            format!("{:.6}", angle.get()),
+            format!("{:.6}", std::f32::consts::PI)
+        );
+    }
+
+    #[test]
+    fn test_to_degrees() {
+        let angle = Angle::radians(std::f32::consts::PI);
+        assert_eq!(angle.to_degrees().round(), 180.0);
+    }
+
+    #[test]
+    fn test_from_f32() {
+        let angle: Angle = 1.5.into();
+        assert_eq!(angle.get(), 1.5);
+    }
+
+    #[test]
+    fn test_f64() {
+        let angle = Angle::degrees(180.0_f64);
+        assert_eq!(angle.get().round(), std::f64::consts::PI.round());
+    }
+
+    #[test]
+    fn test_positive_wraps_negative() {
+        let angle = Angle::degrees(-90.0_f32).positive();
+        assert_eq!(angle.to_degrees().round(), 270.0);
+    }
+
+    #[test]
+    fn test_positive_is_noop_in_range() {
+        let angle = Angle::degrees(37.0_f32).positive();
+        assert_eq!(angle.to_degrees().round(), 37.0);
+    }
+
+    #[test]
+    fn test_signed_wraps_past_pi() {
+        let angle = Angle::degrees(270.0_f32).signed();
+        assert_eq!(angle.to_degrees().round(), -90.0);
+    }
+
+    #[test]
+    fn test_signed_is_noop_in_range() {
+        let angle = Angle::degrees(37.0_f32).signed();
+        assert_eq!(angle.to_degrees().round(), 37.0);
+    }
+}
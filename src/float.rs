@@ -0,0 +1,140 @@
+//! A sealed trait abstracting over the floating point types this crate can
+//! compute with, so the trig functions and [crate::right_angle] types can be
+//! generic over precision instead of hard-coded to [f32].
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A floating point type usable for right-triangle trigonometry.
+///
+/// Implemented for [f32] and [f64]. This trait is sealed and cannot be
+/// implemented for types outside this crate.
+pub trait Float:
+    private::Sealed
+    + Copy
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + core::fmt::Debug
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Div<Output = Self>
+{
+    /// Ratio of a circle's circumference to its diameter, at this type's precision.
+    const PI: Self;
+    /// Default tolerance used when comparing two values of this type for
+    /// practical (not bitwise) equality.
+    const DEFAULT_EPSILON: Self;
+    /// Additive identity, for comparing against zero without an `f32`/`f64` literal.
+    const ZERO: Self;
+
+    fn powi(self, n: i32) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn to_degrees(self) -> Self;
+    fn to_radians(self) -> Self;
+    fn abs(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn is_finite(self) -> bool;
+}
+
+impl Float for f32 {
+    const PI: Self = core::f32::consts::PI;
+    const DEFAULT_EPSILON: Self = 1e-3;
+    const ZERO: Self = 0.0;
+
+    fn powi(self, n: i32) -> Self {
+        crate::ops::powi(self, n)
+    }
+    fn sqrt(self) -> Self {
+        crate::ops::sqrt(self)
+    }
+    fn sin(self) -> Self {
+        crate::ops::sin(self)
+    }
+    fn cos(self) -> Self {
+        crate::ops::cos(self)
+    }
+    fn tan(self) -> Self {
+        crate::ops::tan(self)
+    }
+    fn asin(self) -> Self {
+        crate::ops::asin(self)
+    }
+    fn acos(self) -> Self {
+        crate::ops::acos(self)
+    }
+    fn atan(self) -> Self {
+        crate::ops::atan(self)
+    }
+    fn to_degrees(self) -> Self {
+        f32::to_degrees(self)
+    }
+    fn to_radians(self) -> Self {
+        f32::to_radians(self)
+    }
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+    fn rem_euclid(self, rhs: Self) -> Self {
+        f32::rem_euclid(self, rhs)
+    }
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+}
+
+impl Float for f64 {
+    const PI: Self = core::f64::consts::PI;
+    const DEFAULT_EPSILON: Self = 1e-6;
+    const ZERO: Self = 0.0;
+
+    fn powi(self, n: i32) -> Self {
+        f64::powi(self, n)
+    }
+    fn sqrt(self) -> Self {
+        crate::ops::sqrt64(self)
+    }
+    fn sin(self) -> Self {
+        crate::ops::sin64(self)
+    }
+    fn cos(self) -> Self {
+        crate::ops::cos64(self)
+    }
+    fn tan(self) -> Self {
+        crate::ops::tan64(self)
+    }
+    fn asin(self) -> Self {
+        crate::ops::asin64(self)
+    }
+    fn acos(self) -> Self {
+        crate::ops::acos64(self)
+    }
+    fn atan(self) -> Self {
+        crate::ops::atan64(self)
+    }
+    fn to_degrees(self) -> Self {
+        f64::to_degrees(self)
+    }
+    fn to_radians(self) -> Self {
+        f64::to_radians(self)
+    }
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+    fn rem_euclid(self, rhs: Self) -> Self {
+        f64::rem_euclid(self, rhs)
+    }
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
@@ -6,13 +6,24 @@
 //! - a: Opposite side, or the rise, using rise/run language
 //! - b: Adjacent side, or the run, using rise/run language
 //! - c: Hypotenuse, or the diagonal, using rise/run language
-//! - r: The angle in radians
+//! - r: The angle, in radians (an [Angle] in the [typed] module)
 //!
 //! Method signatures are formed by adding the inputs (from the list above) to the desired outputs.
 //! Thus, if you have a and b, and you need c, call `let c = ab_c(&a,&b);`
 //!
-//! Note, if you have an angle in degrees, simply pass in `angle.to_radians()`.
-//! And, to convert radians to degrees, use `radians.to_degrees()`.
+//! Every function in this module and the [right_angle] types are generic over [Float], which is
+//! implemented for `f32` and `f64` — pick whichever precision your triangle needs.
+//!
+//! The functions in this module work with bare radians values, same as
+//! before [Angle] existed. If you'd rather not mix up degrees and radians at
+//! a call site, see the [typed] module, which wraps the same functions with
+//! [Angle] in place of bare radians. If you already have a raw `f32` radians
+//! value from elsewhere, the [legacy] module keeps the original `f32`-only
+//! functions around unchanged.
+//!
+//! Enable the `libm` feature to route all `f32` and `f64` trig through the
+//! [libm] crate instead of `std`, for bit-identical results across
+//! platforms and Rust versions.
 //!
 //! #Example
 //! ```
@@ -29,11 +40,11 @@
 //! let c = ab_c(&A,&B);
 //! assert_eq!(c, C);
 //!
-//! // Now get the angle (r) in radians
+//! // Now get the angle (r), in radians
 //! let r = ab_r(&A,&B);
 //! assert_eq!(r, R);
 //!
-//! // Convert the angle to degrees (using std::f32::to_degrees)
+//! // Convert the angle to degrees
 //! let r_degrees = r.to_degrees();
 //! assert_eq!(r_degrees.round(), 37.0);
 //!
@@ -51,97 +62,106 @@
 //!
 //! ```
 
+pub use angle::Angle;
+pub mod angle;
+pub use float::Float;
+pub mod float;
+pub mod legacy;
+pub(crate) mod ops;
 pub use right_angle::*;
 pub mod right_angle;
+#[cfg(test)]
+mod test_fixtures;
+pub mod typed;
 
 /// Returns the hypotenuse (c) of a right triangle given the rise (a) and run (b).
 #[inline(always)]
-pub fn ab_c(rise: &f32, run: &f32) -> f32 {
+pub fn ab_c<T: Float>(rise: &T, run: &T) -> T {
     (rise.powi(2) + run.powi(2)).sqrt()
 }
 
 /// Returns the opposite side (a) given the hypotenuse (c) and adjacent side (b).
 #[inline(always)]
-pub fn bc_a(b: &f32, c: &f32) -> f32 {
+pub fn bc_a<T: Float>(b: &T, c: &T) -> T {
     (c.powi(2) - b.powi(2)).sqrt()
 }
 
 /// Returns the adjacent side (b) given the hypotenuse (c) and opposite side (a).
 #[inline(always)]
-pub fn ac_b(a: &f32, c: &f32) -> f32 {
+pub fn ac_b<T: Float>(a: &T, c: &T) -> T {
     (c.powi(2) - a.powi(2)).sqrt()
 }
 
-/// Returns radians (r) given the opposite side (a) and hypotenuse (c).
+/// Returns the angle (r), in radians, given the opposite side (a) and hypotenuse (c).
 #[inline(always)]
-pub fn ac_r(a: &f32, c: &f32) -> f32 {
-    (a / c).asin()
+pub fn ac_r<T: Float>(a: &T, c: &T) -> T {
+    (*a / *c).asin()
 }
 
-/// Returns the radians (r) given the opposite side (a) and adjacent side (b).
+/// Returns the angle (r), in radians, given the opposite side (a) and adjacent side (b).
 #[inline(always)]
-pub fn ab_r(a: &f32, b: &f32) -> f32 {
-    (a / b).atan()
+pub fn ab_r<T: Float>(a: &T, b: &T) -> T {
+    (*a / *b).atan()
 }
 
-/// Returns the radians (r) given the adjacent side (b) and hypotenuse (c).
+/// Returns the angle (r), in radians, given the adjacent side (b) and hypotenuse (c).
 #[inline(always)]
-pub fn bc_r(b: &f32, c: &f32) -> f32 {
-    (b / c).acos()
+pub fn bc_r<T: Float>(b: &T, c: &T) -> T {
+    (*b / *c).acos()
 }
 
-/// Returns the adjacent side (b) given the radians (r) and the opposite side (a).
+/// Returns the adjacent side (b) given the angle (r), in radians, and the opposite side (a).
 #[inline(always)]
-pub fn ra_b(r: &f32, a: &f32) -> f32 {
-    a / r.tan()
+pub fn ra_b<T: Float>(r: &T, a: &T) -> T {
+    *a / r.tan()
 }
 
-/// Returns the hypotenuse (c) given the radians (r) and the opposite side (a).
+/// Returns the hypotenuse (c) given the angle (r), in radians, and the opposite side (a).
 #[inline(always)]
-pub fn ra_c(r: &f32, a: &f32) -> f32 {
-    a / r.sin()
+pub fn ra_c<T: Float>(r: &T, a: &T) -> T {
+    *a / r.sin()
 }
 
-/// Returns the opposite side(a) given the radians (r) and the adjacent side (b).
+/// Returns the opposite side(a) given the angle (r), in radians, and the adjacent side (b).
 #[inline(always)]
-pub fn rb_a(r: &f32, b: &f32) -> f32 {
-    r.tan() * b
+pub fn rb_a<T: Float>(r: &T, b: &T) -> T {
+    r.tan() * *b
 }
 
-/// Returns the hypotenuse (c) given the radians (r) and the adjacent side (b).
+/// Returns the hypotenuse (c) given the angle (r), in radians, and the adjacent side (b).
 #[inline(always)]
-pub fn rb_c(r: &f32, b: &f32) -> f32 {
-    b / r.cos()
+pub fn rb_c<T: Float>(r: &T, b: &T) -> T {
+    *b / r.cos()
 }
 
-/// Given the radians and the hypotenuse (c), return the opposite side (a)
+/// Given the angle (r), in radians, and the hypotenuse (c), return the opposite side (a)
 #[inline(always)]
-pub fn rc_a(r: &f32, c: &f32) -> f32 {
-    c * r.sin()
+pub fn rc_a<T: Float>(r: &T, c: &T) -> T {
+    *c * r.sin()
 }
 
-/// Given the radians and the hypotenuse (c), return the adjacent side (b)
+/// Given the angle (r), in radians, and the hypotenuse (c), return the adjacent side (b)
 #[inline(always)]
-pub fn rc_b(r: &f32, c: &f32) -> f32 {
-    c * r.cos()
+pub fn rc_b<T: Float>(r: &T, c: &T) -> T {
+    *c * r.cos()
 }
 
-/// Given radians and the adjacent (b), calculate the opposite (a) and hypotenuse (c).
-pub fn rb_ac(r: &f32, b: &f32) -> (f32, f32) {
+/// Given the angle (r), in radians, and the adjacent (b), calculate the opposite (a) and hypotenuse (c).
+pub fn rb_ac<T: Float>(r: &T, b: &T) -> (T, T) {
     let a = rb_a(r, b);
     let c = ab_c(&a, b);
     (a, c)
 }
 
-/// Given radians and the opposite (a), calculate the adjacent (c) and hypotenuse (c).
-pub fn ra_bc(r: &f32, a: &f32) -> (f32, f32) {
+/// Given the angle (r), in radians, and the opposite (a), calculate the adjacent (b) and hypotenuse (c).
+pub fn ra_bc<T: Float>(r: &T, a: &T) -> (T, T) {
     let b = ra_b(r, a);
     let c = ab_c(a, &b);
     (b, c)
 }
 
-/// Given radians and the hypotenuse (c), calculate the opposite (a) and adjacent (b).
-pub fn rc_ab(r: &f32, c: &f32) -> (f32, f32) {
+/// Given the angle (r), in radians, and the hypotenuse (c), calculate the opposite (a) and adjacent (b).
+pub fn rc_ab<T: Float>(r: &T, c: &T) -> (T, T) {
     let a = rc_a(r, c);
     let b = rc_b(r, c);
     (a, b)
@@ -150,10 +170,7 @@ pub fn rc_ab(r: &f32, c: &f32) -> (f32, f32) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    const RADIANS_345: f32 = 0.6435011;
-    const A_345: f32 = 3.0;
-    const B_345: f32 = 4.0;
-    const C_345: f32 = 5.0;
+    use crate::test_fixtures::*;
 
     #[test]
     fn test_ab_c() {
@@ -240,4 +257,24 @@ mod tests {
         let result = rc_ab(&RADIANS_345, &C_345);
         assert_eq!(result, (3.0, 4.0));
     }
+
+    // f64 variants, exercising the same 3-4-5 triangle at the other supported precision.
+    #[test]
+    fn test_ab_c_f64() {
+        assert_eq!(ab_c(&A_345_F64, &B_345_F64), C_345_F64);
+    }
+
+    #[test]
+    fn test_ab_r_f64() {
+        assert_eq!(
+            format!("{:.12}", ab_r(&A_345_F64, &B_345_F64)),
+            format!("{:.12}", RADIANS_345_F64)
+        );
+    }
+
+    #[test]
+    fn test_rc_ab_f64() {
+        let result = rc_ab(&RADIANS_345_F64, &C_345_F64);
+        assert_eq!(result, (3.0, 4.0));
+    }
 }
@@ -0,0 +1,145 @@
+//! Internal math primitives backing the `f32` and `f64` [crate::Float]
+//! implementations.
+//!
+//! `f32::sin`, `powi`, `sqrt`, etc. have unspecified precision in Rust, so the
+//! same triangle can round differently across targets and Rust versions. By
+//! default this module simply forwards the trig functions and `sqrt` to those
+//! `std` methods. With the `libm` feature enabled it instead routes them
+//! through the [libm] crate, giving deterministic results across platforms
+//! and Rust versions. [powi] is this module's own in both builds.
+
+#[cfg(not(feature = "libm"))]
+mod imp {
+    pub fn sin(x: f32) -> f32 {
+        f32::sin(x)
+    }
+    pub fn cos(x: f32) -> f32 {
+        f32::cos(x)
+    }
+    pub fn tan(x: f32) -> f32 {
+        f32::tan(x)
+    }
+    pub fn asin(x: f32) -> f32 {
+        f32::asin(x)
+    }
+    pub fn acos(x: f32) -> f32 {
+        f32::acos(x)
+    }
+    pub fn atan(x: f32) -> f32 {
+        f32::atan(x)
+    }
+    pub fn sqrt(x: f32) -> f32 {
+        f32::sqrt(x)
+    }
+    pub fn sin64(x: f64) -> f64 {
+        f64::sin(x)
+    }
+    pub fn cos64(x: f64) -> f64 {
+        f64::cos(x)
+    }
+    pub fn tan64(x: f64) -> f64 {
+        f64::tan(x)
+    }
+    pub fn asin64(x: f64) -> f64 {
+        f64::asin(x)
+    }
+    pub fn acos64(x: f64) -> f64 {
+        f64::acos(x)
+    }
+    pub fn atan64(x: f64) -> f64 {
+        f64::atan(x)
+    }
+    pub fn sqrt64(x: f64) -> f64 {
+        f64::sqrt(x)
+    }
+}
+
+#[cfg(feature = "libm")]
+mod imp {
+    pub fn sin(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+    pub fn cos(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+    pub fn tan(x: f32) -> f32 {
+        libm::tanf(x)
+    }
+    pub fn asin(x: f32) -> f32 {
+        libm::asinf(x)
+    }
+    pub fn acos(x: f32) -> f32 {
+        libm::acosf(x)
+    }
+    pub fn atan(x: f32) -> f32 {
+        libm::atanf(x)
+    }
+    pub fn sqrt(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+    pub fn sin64(x: f64) -> f64 {
+        libm::sin(x)
+    }
+    pub fn cos64(x: f64) -> f64 {
+        libm::cos(x)
+    }
+    pub fn tan64(x: f64) -> f64 {
+        libm::tan(x)
+    }
+    pub fn asin64(x: f64) -> f64 {
+        libm::asin(x)
+    }
+    pub fn acos64(x: f64) -> f64 {
+        libm::acos(x)
+    }
+    pub fn atan64(x: f64) -> f64 {
+        libm::atan(x)
+    }
+    pub fn sqrt64(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+}
+
+pub use imp::{
+    acos, acos64, asin, asin64, atan, atan64, cos, cos64, sin, sin64, sqrt, sqrt64, tan, tan64,
+};
+
+/// `x` raised to the integer power `n`, via repeated squaring. `libm` has no
+/// `powi` of its own, so this is used for both the `std` and `libm` builds to
+/// keep the two paths consistent.
+pub fn powi(x: f32, n: i32) -> f32 {
+    if n == 0 {
+        return 1.0;
+    }
+    let (mut base, exp) = if n < 0 { (1.0 / x, -n) } else { (x, n) };
+    let mut exp = exp as u32;
+    let mut result = 1.0;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powi_positive() {
+        assert_eq!(powi(2.0, 3), 8.0);
+    }
+
+    #[test]
+    fn test_powi_zero() {
+        assert_eq!(powi(5.0, 0), 1.0);
+    }
+
+    #[test]
+    fn test_powi_negative() {
+        assert_eq!(powi(2.0, -1), 0.5);
+    }
+}
@@ -4,14 +4,17 @@
 //!
 //! <br />
 //!
-//! [RightAngle] (and [RightAngleInput]) supports [serde](https://docs.rs/serde/latest/serde/).  So you can `Deserialize`
-//! [RightAngleInput] and  `Serialize` [RightAngle]. Perfect for API applications!
+//! [RightAngle] (and [RightAngleInput]) is generic over [Float], so you can work in `f32`
+//! (the default) or `f64` precision, and supports [serde](https://docs.rs/serde/latest/serde/).
+//! So you can `Deserialize` [RightAngleInput] and  `Serialize` [RightAngle]. Perfect for API
+//! applications!
 //!
 //! <br />
 //!
 //! # Example
 //! ```rust
 //! use pythagoras::right_angle::{RightAngle, RightAngleInput};
+//! use pythagoras::Angle;
 //!
 //! // Using the standard 3,4,5 right triangle
 //! const A:f32 = 3.0;
@@ -19,23 +22,23 @@
 //! const C:f32 = 5.0;
 //! const R: f32 = 0.6435011;
 //!
-//! const RIGHT_ANGLE: RightAngle = RightAngle {
+//! let right_angle = RightAngle {
 //!     rise: A,
 //!     run: B,
 //!     diagonal: C,
-//!     radians: R,
+//!     radians: Angle::radians(R),
 //! };
 //!
 //! // Use one side and the angle to compete the rest of the right angle
 //! let input = RightAngleInput {
-//!     radians: Some(R),
+//!     radians: Some(Angle::radians(R)),
 //!     rise: Some(A),
 //!     run: None,
 //!     diagonal: None,
 //! };
 //!
 //! let result = RightAngle::try_from(&input).expect("Failed to create RightAngle");
-//! assert_eq!(result, RIGHT_ANGLE);
+//! assert_eq!(result, right_angle);
 //!
 //!
 //! // Use two sides to complete the rest of the right angle
@@ -47,20 +50,21 @@
 //! };
 //!
 //! let result = RightAngle::try_from(&input).expect("Failed to create RightAngle");
-//! assert_eq!(result, RIGHT_ANGLE);
+//! assert_eq!(result, right_angle);
 //!
 //!
 //! // Create [RightAngle] by serializing [RightAngleInput] to
 //! // a json string,
 //!  let json = format!(r#"{{ "rise": {}, "run": {} }}"#, A, B);
 //!  let result = RightAngle::try_from(json.as_str()).expect("Failed to convert");
-//!  assert_eq!(result, RIGHT_ANGLE);
+//!  assert_eq!(result, right_angle);
 //! ```
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{ab_c, ab_r, ac_b, ac_r, bc_a, bc_r, ra_bc, rb_ac, rc_ab};
+use crate::typed::{ab_r, ac_r, bc_r, ra_bc, rb_ac, rc_ab};
+use crate::{ab_c, ac_b, bc_a, Angle, Float};
 
 #[derive(Debug, Error)]
 enum RightAngleError {
@@ -70,45 +74,96 @@ enum RightAngleError {
     AngleRequired,
     #[error("Invalid input")]
     InvalidInput,
+    #[error("Supplied radians is inconsistent with the sides provided")]
+    InconsistentAngle,
+    #[error("Side lengths must be finite")]
+    NonFiniteSide,
+    #[error("Side lengths must be positive")]
+    NonPositiveSide,
+    #[error("Angle must be finite")]
+    NonFiniteAngle,
+    #[error("The hypotenuse must be longer than the other side provided")]
+    InvalidTriangle,
+}
+
+/// Rejects a side that is `NaN`/infinite or not strictly positive, so a bad
+/// input fails fast instead of quietly producing a `NaN` diagonal/angle.
+fn validate_side<T: Float>(side: T) -> Result<(), RightAngleError> {
+    if !side.is_finite() {
+        return Err(RightAngleError::NonFiniteSide);
+    }
+    if side <= T::ZERO {
+        return Err(RightAngleError::NonPositiveSide);
+    }
+    Ok(())
+}
+
+/// Returns `supplied`, normalized, after checking that it's within
+/// [Float::DEFAULT_EPSILON] of `derived`; returns `derived`, normalized, when
+/// no angle was supplied.
+fn resolve_radians<T: Float>(
+    supplied: Option<Angle<T>>,
+    derived: Angle<T>,
+) -> Result<Angle<T>, RightAngleError> {
+    let derived = derived.signed();
+    match supplied {
+        Some(r) => {
+            if !r.get().is_finite() {
+                return Err(RightAngleError::NonFiniteAngle);
+            }
+            let r = r.signed();
+            if (r.get() - derived.get()).abs() > T::DEFAULT_EPSILON {
+                Err(RightAngleError::InconsistentAngle)
+            } else {
+                Ok(r)
+            }
+        }
+        None => Ok(derived),
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
-pub struct RightAngle {
-    /// The angle in radians.  You can flip to degrees with `angle.to_degrees()`
-    pub radians: f32,
+pub struct RightAngle<T: Float = f32> {
+    /// The angle opposite the rise.
+    pub radians: Angle<T>,
     /// The opposite side, or `a'
-    pub rise: f32,
+    pub rise: T,
     /// The adjacent side, or `b`
-    pub run: f32,
+    pub run: T,
     /// The hypotenuse, or `c`
-    pub diagonal: f32,
+    pub diagonal: T,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct RightAngleInput {
-    /// The angle in radians.  You can flip to degrees with `angle.to_degrees()`
+pub struct RightAngleInput<T: Float = f32> {
+    /// The angle opposite the rise.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub radians: Option<f32>,
+    pub radians: Option<Angle<T>>,
     /// The opposite side, or `a'
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rise: Option<f32>,
+    pub rise: Option<T>,
     /// The adjacent side, or `b`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub run: Option<f32>,
+    pub run: Option<T>,
     /// The hypotenuse, or `c`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub diagonal: Option<f32>,
+    pub diagonal: Option<T>,
 }
 
-/// Given the angle (in radians) and one side, calculate the other two sides.
+/// Given the angle and one side, calculate the other two sides.
 /// Note, the first side found will be used, in order of rise (a), run (b), diagonal (c).
 /// An error is returned if no side is provided or the ange is not provided.
-pub fn one_side(input: &RightAngleInput) -> Result<RightAngle> {
-    let radians = input.radians.ok_or(RightAngleError::AngleRequired)?;
+pub fn one_side<T: Float>(input: &RightAngleInput<T>) -> Result<RightAngle<T>> {
+    let radians = input
+        .radians
+        .ok_or(RightAngleError::AngleRequired)?
+        .signed();
 
     match (input.rise, input.run, input.diagonal) {
         (Some(a), _, _) => {
             let (b, c) = ra_bc(&radians, &a);
+            validate_side(b)?;
+            validate_side(c)?;
             Ok(RightAngle {
                 radians,
                 rise: a,
@@ -118,6 +173,8 @@ pub fn one_side(input: &RightAngleInput) -> Result<RightAngle> {
         }
         (_, Some(b), _) => {
             let (a, c) = rb_ac(&radians, &b);
+            validate_side(a)?;
+            validate_side(c)?;
             Ok(RightAngle {
                 radians,
                 rise: a,
@@ -127,6 +184,8 @@ pub fn one_side(input: &RightAngleInput) -> Result<RightAngle> {
         }
         (_, _, Some(c)) => {
             let (a, b) = rc_ab(&radians, &c);
+            validate_side(a)?;
+            validate_side(b)?;
             Ok(RightAngle {
                 radians,
                 rise: a,
@@ -141,40 +200,53 @@ pub fn one_side(input: &RightAngleInput) -> Result<RightAngle> {
 /// Given two sides, calculate the third side.
 /// The angle is always calculated from the two sides given
 /// An error is returned if not enough sides are provided.
-pub fn two_sides(input: &RightAngleInput) -> Result<RightAngle> {
+pub fn two_sides<T: Float>(input: &RightAngleInput<T>) -> Result<RightAngle<T>> {
     match (input.rise, input.run, input.diagonal) {
         (Some(a), Some(b), _) => Ok(RightAngle {
             rise: a,
             run: b,
             diagonal: ab_c(&a, &b),
-            radians: input.radians.unwrap_or_else(|| ab_r(&a, &b)),
-        }),
-        (Some(a), _, Some(c)) => Ok(RightAngle {
-            rise: a,
-            run: ac_b(&a, &c),
-            diagonal: c,
-            radians: input.radians.unwrap_or_else(|| ac_r(&a, &c)),
-        }),
-        (_, Some(b), Some(c)) => Ok(RightAngle {
-            rise: bc_a(&b, &c),
-            run: b,
-            diagonal: c,
-            radians: input.radians.unwrap_or_else(|| bc_r(&b, &c)),
+            radians: resolve_radians(input.radians, ab_r(&a, &b))?,
         }),
+        (Some(a), _, Some(c)) => {
+            if c <= a {
+                return Err(RightAngleError::InvalidTriangle.into());
+            }
+            Ok(RightAngle {
+                rise: a,
+                run: ac_b(&a, &c),
+                diagonal: c,
+                radians: resolve_radians(input.radians, ac_r(&a, &c))?,
+            })
+        }
+        (_, Some(b), Some(c)) => {
+            if c <= b {
+                return Err(RightAngleError::InvalidTriangle.into());
+            }
+            Ok(RightAngle {
+                rise: bc_a(&b, &c),
+                run: b,
+                diagonal: c,
+                radians: resolve_radians(input.radians, bc_r(&b, &c))?,
+            })
+        }
         _ => Err(RightAngleError::TooFewSides.into()),
     }
 }
 
 /// Given three sides, calculate the anglee.
 /// The angle is calculated from the rise (a) and the run (b)
-fn three_sides(input: &RightAngleInput) -> Result<RightAngle> {
+fn three_sides<T: Float>(input: &RightAngleInput<T>) -> Result<RightAngle<T>> {
     let rise = input.rise.unwrap();
     let run = input.run.unwrap();
     let diagonal = input.diagonal.unwrap();
-    let radians = match input.radians {
-        Some(r) => r,
-        None => ab_r(&rise, &run),
-    };
+
+    let expected_diagonal = ab_c(&rise, &run);
+    if (expected_diagonal - diagonal).abs() > T::DEFAULT_EPSILON {
+        return Err(RightAngleError::InvalidTriangle.into());
+    }
+
+    let radians = resolve_radians(input.radians, ab_r(&rise, &run))?;
 
     Ok(RightAngle {
         rise,
@@ -189,6 +261,7 @@ fn three_sides(input: &RightAngleInput) -> Result<RightAngle> {
 ///
 /// ```rust
 /// use pythagoras::right_angle::{RightAngle, RightAngleInput};
+/// use pythagoras::Angle;
 ///
 /// // Using the standard 3,4,5 right triangle
 /// const A:f32 = 3.0;
@@ -196,11 +269,11 @@ fn three_sides(input: &RightAngleInput) -> Result<RightAngle> {
 /// const C:f32 = 5.0;
 /// const R: f32 = 0.6435011;
 ///
-/// const RIGHT_ANGLE: RightAngle = RightAngle {
+/// let right_angle = RightAngle {
 ///     rise: A,
 ///     run: B,
 ///     diagonal: C,
-///     radians: R,
+///     radians: Angle::radians(R),
 /// };
 ///
 /// let input = RightAngleInput {
@@ -210,12 +283,12 @@ fn three_sides(input: &RightAngleInput) -> Result<RightAngle> {
 ///     radians: None,
 /// };
 ///
-///  let result = RightAngle::try_from(&input).expect("Failed to convert");
-///  assert_eq!(result, RIGHT_ANGLE);
+///  let result = RightAngle::from_input(&input).expect("Failed to convert");
+///  assert_eq!(result, right_angle);
 /// ```
-impl TryFrom<&RightAngleInput> for RightAngle {
+impl<T: Float> TryFrom<&RightAngleInput<T>> for RightAngle<T> {
     type Error = String;
-    fn try_from(input: &RightAngleInput) -> Result<Self, Self::Error> {
+    fn try_from(input: &RightAngleInput<T>) -> Result<Self, Self::Error> {
         RightAngle::from_input(input).map_err(|e| e.to_string())
     }
 }
@@ -226,6 +299,7 @@ impl TryFrom<&RightAngleInput> for RightAngle {
 ///
 /// ```rust
 /// use pythagoras::right_angle::RightAngle;
+/// use pythagoras::Angle;
 ///
 /// // Using the standard 3,4,5 right triangle
 /// const A:f32 = 3.0;
@@ -233,25 +307,29 @@ impl TryFrom<&RightAngleInput> for RightAngle {
 /// const C:f32 = 5.0;
 /// const R: f32 = 0.6435011;
 ///
-/// const RIGHT_ANGLE: RightAngle = RightAngle {
+/// let right_angle = RightAngle {
 ///     rise: A,
 ///     run: B,
 ///     diagonal: C,
-///     radians: R,
+///     radians: Angle::radians(R),
 /// };
 ///  let json = format!(r#"{{ "rise": {}, "run": {} }}"#, 3.0, 4.0);
 ///  let result = RightAngle::try_from(json.as_str()).expect("Failed to convert");
-///  assert_eq!(result, RIGHT_ANGLE);
+///  assert_eq!(result, right_angle);
 /// ```
-impl TryFrom<&str> for RightAngle {
+impl TryFrom<&str> for RightAngle<f32> {
     type Error = String;
     fn try_from(input: &str) -> Result<Self, Self::Error> {
-        let input = serde_json::from_str::<RightAngleInput>(input).map_err(|e| e.to_string())?;
+        let input =
+            serde_json::from_str::<RightAngleInput<f32>>(input).map_err(|e| e.to_string())?;
         RightAngle::from_input(&input).map_err(|e| e.to_string())
     }
 }
 
-impl RightAngle {
+impl<T: Float> RightAngle<T> {
+    /// Default tolerance used by [RightAngle::approx_eq].
+    pub const DEFAULT_EPSILON: T = T::DEFAULT_EPSILON;
+
     /// There are 2 ways to use this method.
     /// 1. Given 1 side and the angle, find the other 2 sides
     /// 2. Given 2 sides, find the third (and the angle, if not provided)
@@ -261,6 +339,7 @@ impl RightAngle {
     ///
     /// ```rust
     /// use pythagoras::right_angle::{RightAngle, RightAngleInput};
+    /// use pythagoras::Angle;
     ///
     /// // Using the standard 3,4,5 right triangle
     /// const A:f32 = 3.0;
@@ -268,11 +347,11 @@ impl RightAngle {
     /// const C:f32 = 5.0;
     /// const R: f32 = 0.6435011;
     ///
-    /// const RIGHT_ANGLE: RightAngle = RightAngle {
+    /// let right_angle = RightAngle {
     ///     rise: A,
     ///     run: B,
     ///     diagonal: C,
-    ///     radians: R,
+    ///     radians: Angle::radians(R),
     /// };
     ///
     /// let input = RightAngleInput {
@@ -282,9 +361,19 @@ impl RightAngle {
     ///     radians: None,
     /// };
     /// let result = RightAngle::from_input(&input).expect("Failed to convert");
-    /// assert_eq!(result, RIGHT_ANGLE);
+    /// assert_eq!(result, right_angle);
     /// ```
-    pub fn from_input(input: &RightAngleInput) -> Result<Self> {
+    pub fn from_input(input: &RightAngleInput<T>) -> Result<Self> {
+        if let Some(rise) = input.rise {
+            validate_side(rise)?;
+        }
+        if let Some(run) = input.run {
+            validate_side(run)?;
+        }
+        if let Some(diagonal) = input.diagonal {
+            validate_side(diagonal)?;
+        }
+
         let mut side_count = 0;
         if input.rise.is_some() {
             side_count += 1;
@@ -305,33 +394,55 @@ impl RightAngle {
             _ => Err(RightAngleError::InvalidInput.into()),
         }
     }
+
+    /// Compares `self` and `other` within [RightAngle::DEFAULT_EPSILON], instead of requiring
+    /// bitwise-identical floats as `#[derive(PartialEq)]` does.
+    ///
+    /// # Example
+    /// ```rust
+    /// use pythagoras::right_angle::{RightAngle, RightAngleInput};
+    ///
+    /// let a = RightAngle::from_input(&RightAngleInput { rise: Some(3.0_f32), run: Some(4.0), diagonal: None, radians: None }).unwrap();
+    /// let b = RightAngle::from_input(&RightAngleInput { rise: None, run: Some(4.0_f32), diagonal: Some(5.0), radians: None }).unwrap();
+    /// assert!(a.approx_eq(&b));
+    /// ```
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_eps(other, Self::DEFAULT_EPSILON)
+    }
+
+    /// Like [RightAngle::approx_eq], but with a caller-supplied tolerance.
+    pub fn approx_eq_eps(&self, other: &Self, epsilon: T) -> bool {
+        (self.rise - other.rise).abs() <= epsilon
+            && (self.run - other.run).abs() <= epsilon
+            && (self.diagonal - other.diagonal).abs() <= epsilon
+            && (self.radians.get() - other.radians.get()).abs() <= epsilon
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const RADIANS_345: f32 = 0.6435011;
-    const A_345: f32 = 3.0;
-    const B_345: f32 = 4.0;
-    const C_345: f32 = 5.0;
-    const RIGHT_ANGLE: RightAngle = RightAngle {
-        rise: A_345,
-        run: B_345,
-        diagonal: C_345,
-        radians: RADIANS_345,
-    };
+    use crate::test_fixtures::*;
+
+    fn right_angle_345() -> RightAngle {
+        RightAngle {
+            rise: A_345,
+            run: B_345,
+            diagonal: C_345,
+            radians: Angle::radians(RADIANS_345),
+        }
+    }
 
     #[test]
     fn test_try_from_str() {
         let json = format!(r#"{{ "rise": {}, "run": {} }}"#, A_345, B_345);
         let result = RightAngle::try_from(json.as_str()).expect("Failed to convert");
-        assert_eq!(result, RIGHT_ANGLE);
+        assert_eq!(result, right_angle_345());
     }
 
     #[test]
     fn test_empty_err() {
-        let input = RightAngleInput {
+        let input: RightAngleInput = RightAngleInput {
             radians: None,
             rise: None,
             run: None,
@@ -344,7 +455,7 @@ mod tests {
 
     #[test]
     fn test_one_err() {
-        let input = RightAngleInput {
+        let input: RightAngleInput = RightAngleInput {
             radians: None,
             rise: None,
             run: None,
@@ -358,7 +469,7 @@ mod tests {
     #[test]
     fn test_ra() {
         let input = RightAngleInput {
-            radians: Some(RADIANS_345),
+            radians: Some(Angle::radians(RADIANS_345)),
             rise: Some(A_345),
             run: None,
             diagonal: None,
@@ -366,13 +477,13 @@ mod tests {
 
         let result = RightAngle::try_from(&input);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), RIGHT_ANGLE);
+        assert_eq!(result.unwrap(), right_angle_345());
     }
 
     #[test]
     fn test_rb() {
         let input = RightAngleInput {
-            radians: Some(RADIANS_345),
+            radians: Some(Angle::radians(RADIANS_345)),
             rise: None,
             run: Some(B_345),
             diagonal: None,
@@ -380,13 +491,13 @@ mod tests {
 
         let result = RightAngle::try_from(&input);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), RIGHT_ANGLE);
+        assert_eq!(result.unwrap(), right_angle_345());
     }
 
     #[test]
     fn test_rc() {
         let input = RightAngleInput {
-            radians: Some(RADIANS_345),
+            radians: Some(Angle::radians(RADIANS_345)),
             rise: None,
             run: None,
             diagonal: Some(C_345),
@@ -394,12 +505,12 @@ mod tests {
 
         let result = RightAngle::try_from(&input);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), RIGHT_ANGLE);
+        assert_eq!(result.unwrap(), right_angle_345());
     }
 
     #[test]
     fn test_ab() {
-        let input = RightAngleInput {
+        let input: RightAngleInput = RightAngleInput {
             radians: None,
             rise: Some(A_345),
             run: Some(B_345),
@@ -408,12 +519,12 @@ mod tests {
 
         let result = RightAngle::try_from(&input);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), RIGHT_ANGLE);
+        assert_eq!(result.unwrap(), right_angle_345());
     }
     #[test]
     fn test_ac() {
         let input = RightAngleInput {
-            radians: Some(RADIANS_345),
+            radians: Some(Angle::radians(RADIANS_345)),
             rise: Some(A_345),
             run: None,
             diagonal: Some(C_345),
@@ -421,12 +532,12 @@ mod tests {
 
         let result = RightAngle::try_from(&input);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), RIGHT_ANGLE);
+        assert_eq!(result.unwrap(), right_angle_345());
     }
 
     #[test]
     fn test_bc() {
-        let input = RightAngleInput {
+        let input: RightAngleInput = RightAngleInput {
             radians: None,
             rise: None,
             run: Some(B_345),
@@ -435,11 +546,11 @@ mod tests {
 
         let result = RightAngle::try_from(&input);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), RIGHT_ANGLE);
+        assert_eq!(result.unwrap(), right_angle_345());
     }
     #[test]
     fn test_abc() {
-        let input = RightAngleInput {
+        let input: RightAngleInput = RightAngleInput {
             radians: None,
             rise: Some(A_345),
             run: Some(B_345),
@@ -448,6 +559,153 @@ mod tests {
 
         let result = RightAngle::try_from(&input);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), RIGHT_ANGLE);
+        assert_eq!(result.unwrap(), right_angle_345());
+    }
+
+    // f64 variant, exercising the same 3-4-5 triangle at the other supported precision.
+    #[test]
+    fn test_ab_f64() {
+        let input: RightAngleInput<f64> = RightAngleInput {
+            radians: None,
+            rise: Some(3.0),
+            run: Some(4.0),
+            diagonal: None,
+        };
+
+        let result = RightAngle::from_input(&input).expect("Failed to convert");
+        assert_eq!(result.diagonal, 5.0);
+    }
+
+    #[test]
+    fn test_inconsistent_radians_err() {
+        let input = RightAngleInput {
+            radians: Some(Angle::degrees(10.0)),
+            rise: Some(A_345),
+            run: Some(B_345),
+            diagonal: None,
+        };
+
+        let result = RightAngle::try_from(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_non_finite_radians_err() {
+        let input: RightAngleInput = RightAngleInput {
+            radians: Some(Angle::radians(f32::NAN)),
+            rise: Some(A_345),
+            run: Some(B_345),
+            diagonal: None,
+        };
+
+        let result = RightAngle::try_from(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_radians_normalized_on_round_trip() {
+        let out_of_range = RightAngleInput {
+            radians: Some(Angle::radians(RADIANS_345 + 2.0 * std::f32::consts::PI)),
+            rise: Some(A_345),
+            run: Some(B_345),
+            diagonal: None,
+        };
+
+        let result = RightAngle::try_from(&out_of_range).expect("Failed to convert");
+        assert!(result.approx_eq(&right_angle_345()));
+    }
+
+    #[test]
+    fn test_non_finite_side_err() {
+        let input: RightAngleInput = RightAngleInput {
+            radians: None,
+            rise: Some(f32::NAN),
+            run: Some(B_345),
+            diagonal: None,
+        };
+
+        let result = RightAngle::try_from(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_non_positive_side_err() {
+        let input: RightAngleInput = RightAngleInput {
+            radians: None,
+            rise: Some(-A_345),
+            run: Some(B_345),
+            diagonal: None,
+        };
+
+        let result = RightAngle::try_from(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_diagonal_shorter_than_side_err() {
+        let input: RightAngleInput = RightAngleInput {
+            radians: None,
+            rise: Some(C_345),
+            run: None,
+            diagonal: Some(A_345),
+        };
+
+        let result = RightAngle::try_from(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_three_sides_inconsistent_err() {
+        let input: RightAngleInput = RightAngleInput {
+            radians: None,
+            rise: Some(A_345),
+            run: Some(B_345),
+            diagonal: Some(100.0),
+        };
+
+        let result = RightAngle::try_from(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_one_side_zero_angle_err() {
+        let input: RightAngleInput = RightAngleInput {
+            radians: Some(Angle::degrees(0.0)),
+            rise: Some(A_345),
+            run: None,
+            diagonal: None,
+        };
+
+        let result = RightAngle::try_from(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_one_side_right_angle_err() {
+        let input: RightAngleInput = RightAngleInput {
+            radians: Some(Angle::degrees(90.0)),
+            rise: Some(A_345),
+            run: None,
+            diagonal: None,
+        };
+
+        let result = RightAngle::try_from(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = right_angle_345();
+        let mut b = right_angle_345();
+        b.diagonal += RightAngle::<f32>::DEFAULT_EPSILON / 2.0;
+        assert!(a.approx_eq(&b));
+    }
+
+    #[test]
+    fn test_approx_eq_outside_epsilon() {
+        let a = right_angle_345();
+        let mut b = right_angle_345();
+        b.diagonal += RightAngle::<f32>::DEFAULT_EPSILON * 10.0;
+        assert!(!a.approx_eq(&b));
     }
 }
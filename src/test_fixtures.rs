@@ -0,0 +1,16 @@
+//! Shared 3-4-5 right triangle fixtures for `#[cfg(test)]` modules across the
+//! crate. [crate::typed] is a thin wrapper around the crate root,
+//! [crate::legacy] is its `f32`-only counterpart, and [crate::right_angle]
+//! builds on top of [crate::typed] and the root, so their tests all exercise
+//! the same triangle and would otherwise redeclare these constants
+//! identically in four places.
+
+pub(crate) const RADIANS_345: f32 = 0.6435011;
+pub(crate) const A_345: f32 = 3.0;
+pub(crate) const B_345: f32 = 4.0;
+pub(crate) const C_345: f32 = 5.0;
+
+pub(crate) const RADIANS_345_F64: f64 = 0.6435011087932844;
+pub(crate) const A_345_F64: f64 = 3.0;
+pub(crate) const B_345_F64: f64 = 4.0;
+pub(crate) const C_345_F64: f64 = 5.0;
@@ -0,0 +1,155 @@
+//! [Angle]-typed equivalents of the bare-radians functions in the crate root,
+//! so degrees and radians can never be confused at a call site: build an
+//! [Angle] with `Angle::radians(..)` or `Angle::degrees(..)` and pass it here
+//! instead of a bare float.
+//!
+//! These are generic over [Float], just like the crate root.
+
+use crate::{Angle, Float};
+
+/// Returns the angle (r) given the opposite side (a) and hypotenuse (c).
+#[inline(always)]
+pub fn ac_r<T: Float>(a: &T, c: &T) -> Angle<T> {
+    Angle::radians(crate::ac_r(a, c))
+}
+
+/// Returns the angle (r) given the opposite side (a) and adjacent side (b).
+#[inline(always)]
+pub fn ab_r<T: Float>(a: &T, b: &T) -> Angle<T> {
+    Angle::radians(crate::ab_r(a, b))
+}
+
+/// Returns the angle (r) given the adjacent side (b) and hypotenuse (c).
+#[inline(always)]
+pub fn bc_r<T: Float>(b: &T, c: &T) -> Angle<T> {
+    Angle::radians(crate::bc_r(b, c))
+}
+
+/// Returns the adjacent side (b) given the angle (r) and the opposite side (a).
+#[inline(always)]
+pub fn ra_b<T: Float>(r: &Angle<T>, a: &T) -> T {
+    crate::ra_b(&r.get(), a)
+}
+
+/// Returns the hypotenuse (c) given the angle (r) and the opposite side (a).
+#[inline(always)]
+pub fn ra_c<T: Float>(r: &Angle<T>, a: &T) -> T {
+    crate::ra_c(&r.get(), a)
+}
+
+/// Returns the opposite side(a) given the angle (r) and the adjacent side (b).
+#[inline(always)]
+pub fn rb_a<T: Float>(r: &Angle<T>, b: &T) -> T {
+    crate::rb_a(&r.get(), b)
+}
+
+/// Returns the hypotenuse (c) given the angle (r) and the adjacent side (b).
+#[inline(always)]
+pub fn rb_c<T: Float>(r: &Angle<T>, b: &T) -> T {
+    crate::rb_c(&r.get(), b)
+}
+
+/// Given the angle and the hypotenuse (c), return the opposite side (a)
+#[inline(always)]
+pub fn rc_a<T: Float>(r: &Angle<T>, c: &T) -> T {
+    crate::rc_a(&r.get(), c)
+}
+
+/// Given the angle and the hypotenuse (c), return the adjacent side (b)
+#[inline(always)]
+pub fn rc_b<T: Float>(r: &Angle<T>, c: &T) -> T {
+    crate::rc_b(&r.get(), c)
+}
+
+/// Given the angle and the adjacent (b), calculate the opposite (a) and hypotenuse (c).
+pub fn rb_ac<T: Float>(r: &Angle<T>, b: &T) -> (T, T) {
+    crate::rb_ac(&r.get(), b)
+}
+
+/// Given the angle and the opposite (a), calculate the adjacent (b) and hypotenuse (c).
+pub fn ra_bc<T: Float>(r: &Angle<T>, a: &T) -> (T, T) {
+    crate::ra_bc(&r.get(), a)
+}
+
+/// Given the angle and the hypotenuse (c), calculate the opposite (a) and adjacent (b).
+pub fn rc_ab<T: Float>(r: &Angle<T>, c: &T) -> (T, T) {
+    crate::rc_ab(&r.get(), c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::*;
+
+    #[test]
+    fn test_ac_r() {
+        assert_eq!(
+            format!("{:.6}", ac_r(&A_345, &C_345).get()),
+            format!("{:.6}", RADIANS_345)
+        );
+    }
+
+    #[test]
+    fn test_ab_r() {
+        assert_eq!(
+            format!("{:.6}", ab_r(&A_345, &B_345).get()),
+            format!("{:.6}", RADIANS_345)
+        );
+    }
+
+    #[test]
+    fn test_bc_r() {
+        assert_eq!(
+            format!("{:.6}", bc_r(&B_345, &C_345).get()),
+            format!("{:.6}", RADIANS_345)
+        );
+    }
+
+    #[test]
+    fn test_ra_b() {
+        assert_eq!(ra_b(&Angle::radians(RADIANS_345), &A_345), B_345);
+    }
+
+    #[test]
+    fn test_ra_c() {
+        assert_eq!(ra_c(&Angle::radians(RADIANS_345), &A_345), C_345);
+    }
+
+    #[test]
+    fn test_rb_a() {
+        assert_eq!(rb_a(&Angle::radians(RADIANS_345), &B_345), A_345);
+    }
+
+    #[test]
+    fn test_rb_c() {
+        assert_eq!(rb_c(&Angle::radians(RADIANS_345), &B_345), C_345);
+    }
+
+    #[test]
+    fn test_rc_a() {
+        assert_eq!(rc_a(&Angle::radians(RADIANS_345), &C_345), A_345);
+    }
+
+    #[test]
+    fn test_rc_b() {
+        assert_eq!(rc_b(&Angle::radians(RADIANS_345), &C_345), B_345);
+    }
+
+    #[test]
+    fn test_rb_ac() {
+        let result = rb_ac(&Angle::radians(RADIANS_345), &B_345);
+        assert_eq!(result, (A_345, C_345));
+    }
+
+    #[test]
+    fn test_ra_bc() {
+        let result = ra_bc(&Angle::radians(RADIANS_345), &A_345);
+        assert_eq!(result, (B_345, C_345));
+    }
+
+    #[test]
+    fn test_rc_ab() {
+        let result = rc_ab(&Angle::radians(RADIANS_345), &C_345);
+        assert_eq!(result, (3.0, 4.0));
+    }
+}